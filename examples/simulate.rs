@@ -1,7 +1,7 @@
 // Example: Simulate rotary encoder behavior without hardware
 // This example demonstrates the encoder logic without requiring ESP32 hardware
 
-use rust_rotary_encoder::{RangeMode, RotaryEncoder};
+use rust_rotary_encoder::{RangeMode, RotaryEncoder, RotaryEncoderConfig};
 
 fn main() {
     // Initialize a simple logger for the simulation
@@ -14,7 +14,11 @@ fn main() {
     println!("==============================================");
     
     // Create encoder with 0-359 degree range
-    let encoder = RotaryEncoder::new(0, 359, 1, false, RangeMode::Wrap);
+    let encoder = RotaryEncoder::new(RotaryEncoderConfig {
+        max_val: 359,
+        range_mode: RangeMode::Wrap,
+        ..Default::default()
+    });
     
     println!("Initial angle: {} degrees", encoder.angle());
     
@@ -83,7 +87,11 @@ fn main() {
     }
     
     println!("\n--- Testing BOUNDED mode ---");
-    let bounded_encoder = RotaryEncoder::new(0, 10, 1, false, RangeMode::Bounded);
+    let bounded_encoder = RotaryEncoder::new(RotaryEncoderConfig {
+        max_val: 10,
+        range_mode: RangeMode::Bounded,
+        ..Default::default()
+    });
     bounded_encoder.set_value(9);
     println!("Bounded encoder at 9 (max=10)");
     
@@ -0,0 +1,100 @@
+// Pluggable storage for the values `RotaryEncoder` shares between the
+// pin-interrupt producer and whatever reads `value()`/`angle()`. Most
+// targets (ESP32, desktop simulation) have `core::sync::atomic` for every
+// width used here, so that's the default. Some single-core, bare-metal
+// MCUs don't, so the `critical-section` feature swaps in a plain cell
+// guarded by a global critical section instead — same "one writer at a
+// time" guarantee, without requiring atomics.
+
+#[cfg(not(feature = "critical-section"))]
+use core::sync::atomic::{AtomicI32, AtomicU8, Ordering};
+
+#[cfg(feature = "critical-section")]
+use core::cell::UnsafeCell;
+
+/// A single integer-sized cell, safe to read and write from an interrupt
+/// handler and a regular thread at the same time without a blocking lock.
+pub(crate) trait IntCell<T: Copy> {
+    fn new(value: T) -> Self;
+    fn load(&self) -> T;
+    fn store(&self, value: T);
+}
+
+#[cfg(not(feature = "critical-section"))]
+pub(crate) struct U8Cell(AtomicU8);
+
+#[cfg(not(feature = "critical-section"))]
+pub(crate) struct I32Cell(AtomicI32);
+
+#[cfg(not(feature = "critical-section"))]
+impl IntCell<u8> for U8Cell {
+    fn new(value: u8) -> Self {
+        Self(AtomicU8::new(value))
+    }
+
+    fn load(&self) -> u8 {
+        self.0.load(Ordering::SeqCst)
+    }
+
+    fn store(&self, value: u8) {
+        self.0.store(value, Ordering::SeqCst);
+    }
+}
+
+#[cfg(not(feature = "critical-section"))]
+impl IntCell<i32> for I32Cell {
+    fn new(value: i32) -> Self {
+        Self(AtomicI32::new(value))
+    }
+
+    fn load(&self) -> i32 {
+        self.0.load(Ordering::SeqCst)
+    }
+
+    fn store(&self, value: i32) {
+        self.0.store(value, Ordering::SeqCst);
+    }
+}
+
+#[cfg(feature = "critical-section")]
+pub(crate) struct U8Cell(UnsafeCell<u8>);
+
+#[cfg(feature = "critical-section")]
+pub(crate) struct I32Cell(UnsafeCell<i32>);
+
+// SAFETY: every access goes through `critical_section::with`, so only one
+// side (ISR or consumer) ever touches the cell's contents at a time.
+#[cfg(feature = "critical-section")]
+unsafe impl Sync for U8Cell {}
+#[cfg(feature = "critical-section")]
+unsafe impl Sync for I32Cell {}
+
+#[cfg(feature = "critical-section")]
+impl IntCell<u8> for U8Cell {
+    fn new(value: u8) -> Self {
+        Self(UnsafeCell::new(value))
+    }
+
+    fn load(&self) -> u8 {
+        critical_section::with(|_| unsafe { *self.0.get() })
+    }
+
+    fn store(&self, value: u8) {
+        critical_section::with(|_| unsafe { *self.0.get() = value });
+    }
+}
+
+#[cfg(feature = "critical-section")]
+impl IntCell<i32> for I32Cell {
+    fn new(value: i32) -> Self {
+        Self(UnsafeCell::new(value))
+    }
+
+    fn load(&self) -> i32 {
+        critical_section::with(|_| unsafe { *self.0.get() })
+    }
+
+    fn store(&self, value: i32) {
+        critical_section::with(|_| unsafe { *self.0.get() = value });
+    }
+}
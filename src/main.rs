@@ -5,7 +5,7 @@ use esp_idf_hal::gpio::{InterruptType, PinDriver, Pull};
 use esp_idf_hal::peripherals::Peripherals;
 use esp_idf_svc::log::EspLogger;
 use log::info;
-use rust_rotary_encoder::{RangeMode, RotaryEncoder};
+use rust_rotary_encoder::{RangeMode, RotaryEncoder, RotaryEncoderConfig, StepMode};
 use std::sync::Arc;
 use std::thread;
 use std::time::Duration;
@@ -37,13 +37,12 @@ fn main() -> anyhow::Result<()> {
 
     // Create the rotary encoder instance
     // Using angle range 0-359 degrees with wrap mode
-    let encoder = Arc::new(RotaryEncoder::new(
-        0,               // min_val: 0 degrees
-        359,             // max_val: 359 degrees
-        1,               // increment: 1 degree per click
-        false,           // reverse: not reversed
-        RangeMode::Wrap, // wrap around at 360 degrees
-    ));
+    let encoder = Arc::new(RotaryEncoder::new(RotaryEncoderConfig {
+        max_val: 359,          // 0-359 degrees
+        range_mode: RangeMode::Wrap, // wrap around at 360 degrees
+        step_mode: StepMode::Full,   // one full Gray-code cycle per detent
+        ..Default::default()
+    }));
 
     info!("Rotary encoder initialized:");
     info!("  Range: 0-359 degrees (wrap mode)");
@@ -1,8 +1,56 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
 // MIT License
 // Based on Ben Buxton's rotary encoder algorithm
 // Reference: https://github.com/miketeachman/micropython-rotary
+//
+// The decoder and range logic below are `no_std`-first: disable the default
+// `std` feature (used by the ESP32 app and `examples/simulate.rs`) to build
+// for a bare-metal target. `std` additionally pulls in the event sink,
+// push-button support, and velocity-based acceleration, all of which need
+// `Arc`/`Instant`. On single-core MCUs whose atomics don't cover
+// every width used here, also enable the `critical-section` feature.
+
+mod cell;
+
+#[cfg(feature = "std")]
+use std::cell::UnsafeCell;
+#[cfg(feature = "std")]
+use std::sync::atomic::{AtomicBool, AtomicI32, AtomicU64, AtomicUsize, Ordering};
+#[cfg(feature = "std")]
+use std::sync::Arc;
+#[cfg(feature = "std")]
+use std::time::Instant;
+
+use cell::{I32Cell, IntCell, U8Cell};
 
-use std::sync::atomic::{AtomicI32, AtomicU8, Ordering};
+// `log::*!` calls route through these so the no_std core can build (and
+// run) without pulling in the `log` crate at all; bare-metal targets often
+// route diagnostics through something else (defmt, RTT) or skip them.
+macro_rules! log_info {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "log")]
+        log::info!($($arg)*);
+    };
+}
+macro_rules! log_debug {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "log")]
+        log::debug!($($arg)*);
+    };
+}
+macro_rules! log_trace {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "log")]
+        log::trace!($($arg)*);
+    };
+}
+macro_rules! log_warn {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "log")]
+        log::warn!($($arg)*);
+    };
+}
 
 // Direction indicators
 const DIR_CW: u8 = 0x10;  // Clockwise step
@@ -35,6 +83,29 @@ const TRANSITION_TABLE: [[u8; 4]; 8] = [
     [R_START,     R_START,    R_START,    R_START],        // R_ILLEGAL
 ];
 
+// Half-step states (Ben Buxton half-step machine)
+const R_H_START: u8 = 0x0;
+const R_H_CCW_BEGIN: u8 = 0x1;
+const R_H_CW_BEGIN: u8 = 0x2;
+const R_H_START_M: u8 = 0x3;
+const R_H_CW_BEGIN_M: u8 = 0x4;
+const R_H_CCW_BEGIN_M: u8 = 0x5;
+
+// Half-step transition table: a step is emitted at both the 00 and 11
+// rest positions, doubling the resolution of the full-step table above.
+// Its direction convention is the reverse of the full-step table's: here
+// 11 -> 01 -> 00 -> 10 -> 11 decodes as clockwise.
+// [current_state][clk_dt_pins] = next_state
+const HALF_STEP_TRANSITION_TABLE: [[u8; 4]; 6] = [
+    // CLK/DT: 00                     01                 10                 11
+    [R_H_START_M,              R_H_CW_BEGIN,      R_H_CCW_BEGIN,     R_H_START],        // R_H_START
+    [R_H_START_M | DIR_CCW,    R_H_START,         R_H_CCW_BEGIN,     R_H_START],        // R_H_CCW_BEGIN
+    [R_H_START_M | DIR_CW,     R_H_CW_BEGIN,      R_H_START,         R_H_START],        // R_H_CW_BEGIN
+    [R_H_START_M,              R_H_CCW_BEGIN_M,   R_H_CW_BEGIN_M,    R_H_START],        // R_H_START_M
+    [R_H_START_M,              R_H_START_M,       R_H_CW_BEGIN_M,    R_H_START | DIR_CW], // R_H_CW_BEGIN_M
+    [R_H_START_M,              R_H_CCW_BEGIN_M,   R_H_START_M,       R_H_START | DIR_CCW], // R_H_CCW_BEGIN_M
+];
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum RangeMode {
     Unbounded,
@@ -42,104 +113,506 @@ pub enum RangeMode {
     Bounded,
 }
 
+/// Selects which Gray-code transition table decodes the encoder's pins.
+///
+/// `Full` emits one step per detent, matching encoders that complete a
+/// single Gray-code cycle per click. `Half` emits a step at both the 00
+/// and 11 rest positions, doubling resolution for encoders that only
+/// produce half a cycle per detent.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StepMode {
+    Full,
+    Half,
+}
+
+/// Optional velocity-based acceleration: when detents keep arriving faster
+/// than `threshold_us` apart (and in the same direction), the applied
+/// increment is scaled up to `max_multiplier`x so fast spins cover more
+/// ground than slow, deliberate ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Acceleration {
+    pub threshold_us: u64,
+    pub max_multiplier: u32,
+}
+
+/// Direction of a completed detent, as reported through the event sink.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Clockwise,
+    CounterClockwise,
+}
+
+/// A single completed detent, as pushed into the event sink by `process_pins`.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy)]
+pub struct EncoderEvent {
+    pub direction: Direction,
+    pub value: i32,
+    pub timestamp: Instant,
+}
+
+/// A debounced push-button transition or hold, as pushed into the event sink
+/// by `Button::process_button`.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ButtonEvent {
+    /// The button was just pressed (after debouncing).
+    Down,
+    /// The button was just released (after debouncing).
+    Up,
+    /// The button was pressed and released again without a `LongPress` having
+    /// fired for that press.
+    Click,
+    /// The button has been held down for at least `long_press_ms`.
+    LongPress,
+}
+
+/// An event delivered through the shared sink: either a rotation detent or a
+/// button transition. Keeping both on one sink lets a consumer observe the
+/// encoder and its push switch in the order they actually happened.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy)]
+pub enum Event {
+    Rotation(EncoderEvent),
+    Button(ButtonEvent),
+}
+
+#[cfg(feature = "std")]
+impl Event {
+    /// Convenience accessor for callers that only care about button events.
+    pub fn as_button(&self) -> Option<ButtonEvent> {
+        match self {
+            Event::Button(button_event) => Some(*button_event),
+            Event::Rotation(_) => None,
+        }
+    }
+}
+
+// Ring buffer capacity for the event sink. Sized generously relative to the
+// rate a human can spin a detent so a slow consumer rarely loses events.
+#[cfg(feature = "std")]
+const EVENT_QUEUE_CAPACITY: usize = 32;
+
+// One ring slot, tagged with the enqueue/dequeue lap it's currently valid
+// for. `sequence == pos` means the slot is free and claimable by whichever
+// producer is enqueuing at position `pos`; `sequence == pos + 1` means it
+// holds a value ready for the consumer dequeuing at position `pos`. This is
+// Dmitry Vyukov's bounded MPMC queue design, simplified to a single
+// consumer (see `EventQueue::pop`) but keeping the general slot protocol so
+// multiple producers (the rotary ISR and a `Button`'s ISR) can reserve
+// slots concurrently without racing each other's writes.
+#[cfg(feature = "std")]
+struct Slot {
+    sequence: AtomicUsize,
+    value: UnsafeCell<Option<Event>>,
+}
+
+// A bounded multi-producer/single-consumer ring buffer carrying `Event`s
+// from `process_pins`/`Button::process_button` (the producers, running in
+// ISR context — potentially two independent ISRs sharing one queue) to a
+// consumer thread that drains it via `EventConsumer`. The producer side
+// never allocates or blocks: when the buffer is full it drops the newest
+// event and bumps `dropped` rather than evicting the oldest one, still the
+// queue-from-ISR pattern used by hardware rotary-encoder drivers, just
+// lossy at the new end instead of the old one.
+#[cfg(feature = "std")]
+struct EventQueue {
+    slots: [Slot; EVENT_QUEUE_CAPACITY],
+    enqueue_pos: AtomicUsize,
+    dequeue_pos: AtomicUsize,
+    dropped: AtomicU64,
+}
+
+// SAFETY: every slot is only ever written to (or read from) after a
+// producer/the consumer has won the `sequence`-gated handoff in `push`/`pop`
+// below, so at most one side ever touches a given slot's `value` at a time.
+#[cfg(feature = "std")]
+unsafe impl Sync for EventQueue {}
+
+#[cfg(feature = "std")]
+impl EventQueue {
+    fn new() -> Self {
+        Self {
+            slots: std::array::from_fn(|i| Slot {
+                sequence: AtomicUsize::new(i),
+                value: UnsafeCell::new(None),
+            }),
+            enqueue_pos: AtomicUsize::new(0),
+            dequeue_pos: AtomicUsize::new(0),
+            dropped: AtomicU64::new(0),
+        }
+    }
+
+    /// Safe to call concurrently from multiple producers (e.g. the rotary
+    /// ISR and a `Button`'s ISR sharing this queue): each reserves a
+    /// distinct slot via a CAS loop on `enqueue_pos` before writing to it,
+    /// so two producers can never write the same slot at once.
+    fn push(&self, event: Event) {
+        let mut pos = self.enqueue_pos.load(Ordering::Relaxed);
+        let slot = loop {
+            let slot = &self.slots[pos % EVENT_QUEUE_CAPACITY];
+            let seq = slot.sequence.load(Ordering::Acquire);
+            let diff = seq as isize - pos as isize;
+
+            if diff == 0 {
+                // Slot is free for this lap; try to claim it.
+                match self.enqueue_pos.compare_exchange_weak(
+                    pos,
+                    pos + 1,
+                    Ordering::Relaxed,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => break slot,
+                    Err(current) => pos = current,
+                }
+            } else if diff < 0 {
+                // The consumer hasn't freed this slot yet: queue is full.
+                self.dropped.fetch_add(1, Ordering::Relaxed);
+                return;
+            } else {
+                // Another producer already claimed this position; reload.
+                pos = self.enqueue_pos.load(Ordering::Relaxed);
+            }
+        };
+
+        unsafe {
+            *slot.value.get() = Some(event);
+        }
+        slot.sequence.store(pos + 1, Ordering::Release);
+    }
+
+    fn pop(&self) -> Option<Event> {
+        let pos = self.dequeue_pos.load(Ordering::Relaxed);
+        let slot = &self.slots[pos % EVENT_QUEUE_CAPACITY];
+        let seq = slot.sequence.load(Ordering::Acquire);
+        let diff = seq as isize - (pos + 1) as isize;
+
+        if diff != 0 {
+            // Nothing enqueued at this position yet: queue is empty.
+            return None;
+        }
+
+        let event = unsafe { (*slot.value.get()).take() };
+        slot.sequence
+            .store(pos + EVENT_QUEUE_CAPACITY, Ordering::Release);
+        self.dequeue_pos.store(pos + 1, Ordering::Relaxed);
+        event
+    }
+
+    fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+}
+
+/// Consumer handle returned by `RotaryEncoder::with_event_sink`. Drain it
+/// from a regular thread (not the ISR) to receive rotation and button events
+/// without losing direction or missing detents between polls of `value()`.
+#[cfg(feature = "std")]
+pub struct EventConsumer {
+    queue: Arc<EventQueue>,
+}
+
+#[cfg(feature = "std")]
+impl EventConsumer {
+    /// Pop the oldest undelivered event, if any.
+    pub fn recv(&self) -> Option<Event> {
+        self.queue.pop()
+    }
+
+    /// Number of events dropped because the consumer fell behind.
+    pub fn dropped_events(&self) -> u64 {
+        self.queue.dropped()
+    }
+}
+
+// Sentinel for "no step recorded yet" in the nanosecond timestamps below.
+#[cfg(feature = "std")]
+const NO_TIMESTAMP: u64 = u64::MAX;
+
+// `process_pins` runs in ISR context (see `EventQueue`'s doc comment), so
+// this can't take a blocking `Mutex` without risking unbounded ISR latency
+// or a deadlock against whatever else holds the lock when the interrupt
+// fires. Each field is instead a plain atomic, written only by the single
+// ISR that drives this encoder's `process_pins`/`acceleration_multiplier`
+// calls, mirroring how `RotaryEncoder::state`/`value` avoid a lock.
+#[cfg(feature = "std")]
+struct AccelState {
+    // Nanoseconds since `RotaryEncoder::accel_epoch`, or `NO_TIMESTAMP`.
+    last_step_at_nanos: AtomicU64,
+    last_sign: AtomicI32,
+}
+
 pub struct RotaryEncoder {
-    state: AtomicU8,
-    value: AtomicI32,
+    state: U8Cell,
+    value: I32Cell,
     min_val: i32,
     max_val: i32,
     incr: i32,
     reverse: i32,
     range_mode: RangeMode,
+    step_mode: StepMode,
+    steps_per_period: u32,
+    sub_step_accum: I32Cell,
+    acceleration: Option<Acceleration>,
+    #[cfg(feature = "std")]
+    accel_epoch: Instant,
+    #[cfg(feature = "std")]
+    accel_state: AccelState,
+    #[cfg(feature = "std")]
+    event_queue: Option<Arc<EventQueue>>,
+}
+
+/// Construction parameters for `RotaryEncoder::new`/`with_event_sink`,
+/// grouped into one struct so adding a knob doesn't grow the constructor's
+/// argument list. Start from `RotaryEncoderConfig::default()` and override
+/// only the fields that differ, via struct-update syntax:
+///
+/// ```
+/// # use rust_rotary_encoder::{RotaryEncoder, RotaryEncoderConfig, RangeMode};
+/// let encoder = RotaryEncoder::new(RotaryEncoderConfig {
+///     max_val: 359,
+///     range_mode: RangeMode::Wrap,
+///     ..Default::default()
+/// });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RotaryEncoderConfig {
+    pub min_val: i32,
+    pub max_val: i32,
+    pub incr: i32,
+    pub reverse: bool,
+    pub range_mode: RangeMode,
+    pub step_mode: StepMode,
+    pub steps_per_period: u32,
+    pub acceleration: Option<Acceleration>,
+}
+
+impl Default for RotaryEncoderConfig {
+    fn default() -> Self {
+        Self {
+            min_val: 0,
+            max_val: i32::MAX,
+            incr: 1,
+            reverse: false,
+            range_mode: RangeMode::Unbounded,
+            step_mode: StepMode::Full,
+            steps_per_period: 1,
+            acceleration: None,
+        }
+    }
 }
 
 impl RotaryEncoder {
-    pub fn new(
-        min_val: i32,
-        max_val: i32,
-        incr: i32,
-        reverse: bool,
-        range_mode: RangeMode,
-    ) -> Self {
-        log::info!("Creating RotaryEncoder: min={}, max={}, incr={}, reverse={}, mode={:?}",
-                   min_val, max_val, incr, reverse, range_mode);
-        
+    /// `steps_per_period` declares how many decoded Gray-code sub-steps make
+    /// up one logical step, so a high-resolution encoder that produces
+    /// several transitions per detent doesn't over-report. Pass `1` for one
+    /// count per detent. `acceleration`, if set, scales the applied
+    /// increment up when detents arrive in quick succession.
+    pub fn new(config: RotaryEncoderConfig) -> Self {
+        let RotaryEncoderConfig {
+            min_val,
+            max_val,
+            incr,
+            reverse,
+            range_mode,
+            step_mode,
+            steps_per_period,
+            acceleration,
+        } = config;
+
+        log_info!("Creating RotaryEncoder: min={}, max={}, incr={}, reverse={}, mode={:?}, step_mode={:?}, steps_per_period={}, acceleration={:?}",
+                   min_val, max_val, incr, reverse, range_mode, step_mode, steps_per_period, acceleration);
+
         Self {
-            state: AtomicU8::new(R_START),
-            value: AtomicI32::new(min_val),
+            state: U8Cell::new(R_START),
+            value: I32Cell::new(min_val),
             min_val,
             max_val,
             incr,
             reverse: if reverse { -1 } else { 1 },
             range_mode,
+            step_mode,
+            steps_per_period,
+            sub_step_accum: I32Cell::new(0),
+            acceleration,
+            #[cfg(feature = "std")]
+            accel_epoch: Instant::now(),
+            #[cfg(feature = "std")]
+            accel_state: AccelState {
+                last_step_at_nanos: AtomicU64::new(NO_TIMESTAMP),
+                last_sign: AtomicI32::new(0),
+            },
+            #[cfg(feature = "std")]
+            event_queue: None,
         }
     }
 
+    /// Like `new`, but also wires up an event sink: every completed detent
+    /// pushes an `EncoderEvent` into a bounded ring buffer that the returned
+    /// `EventConsumer` can drain from another thread, instead of relying on
+    /// polling `value()` and losing direction/count information between
+    /// polls.
+    #[cfg(feature = "std")]
+    pub fn with_event_sink(config: RotaryEncoderConfig) -> (Self, EventConsumer) {
+        let mut encoder = Self::new(config);
+        let queue = Arc::new(EventQueue::new());
+        encoder.event_queue = Some(queue.clone());
+        (encoder, EventConsumer { queue })
+    }
+
+    /// Scale factor to apply to the next increment, based on how quickly
+    /// detents are arriving. Resets to 1x once motion slows past
+    /// `threshold_us` or the direction reverses.
+    #[cfg(feature = "std")]
+    fn acceleration_multiplier(&self, direction_sign: i32) -> i32 {
+        let Some(acceleration) = self.acceleration else {
+            return 1;
+        };
+
+        let now_nanos = self.nanos_since_accel_epoch(Instant::now());
+        let last_step_at_nanos = self.accel_state.last_step_at_nanos.load(Ordering::SeqCst);
+        let last_sign = self.accel_state.last_sign.load(Ordering::SeqCst);
+
+        let multiplier = if last_step_at_nanos != NO_TIMESTAMP && last_sign == direction_sign {
+            let elapsed_us = now_nanos.saturating_sub(last_step_at_nanos) / 1_000;
+            if elapsed_us < acceleration.threshold_us {
+                let scale = acceleration.threshold_us / elapsed_us.max(1);
+                scale.min(acceleration.max_multiplier as u64).max(1) as i32
+            } else {
+                1
+            }
+        } else {
+            1
+        };
+
+        self.accel_state
+            .last_step_at_nanos
+            .store(now_nanos, Ordering::SeqCst);
+        self.accel_state.last_sign.store(direction_sign, Ordering::SeqCst);
+
+        multiplier
+    }
+
+    #[cfg(feature = "std")]
+    fn nanos_since_accel_epoch(&self, instant: Instant) -> u64 {
+        instant.saturating_duration_since(self.accel_epoch).as_nanos() as u64
+    }
+
+    /// No monotonic clock source is threaded into the no_std core yet, so
+    /// acceleration scaling is a no-op off `std`; leave `acceleration` as
+    /// `None` on bare-metal builds.
+    #[cfg(not(feature = "std"))]
+    fn acceleration_multiplier(&self, _direction_sign: i32) -> i32 {
+        // `acceleration` is only read by the `std` implementation above;
+        // touch it here so it isn't flagged as dead code on a no_std build.
+        let _ = self.acceleration;
+        1
+    }
+
+    /// Attach a debounced push-button switch that reports through this
+    /// encoder's event sink, so rotation and button events are observed
+    /// through a single `EventConsumer`. Returns `None` if the encoder was
+    /// built with `new` rather than `with_event_sink`, since there is no
+    /// sink to share.
+    #[cfg(feature = "std")]
+    pub fn attach_button(&self, debounce_ms: u64, long_press_ms: u64) -> Option<Button> {
+        let queue = self.event_queue.clone()?;
+        Some(Button::new(queue, debounce_ms, long_press_ms))
+    }
+
     pub fn value(&self) -> i32 {
-        self.value.load(Ordering::SeqCst)
+        self.value.load()
     }
 
     pub fn set_value(&self, value: i32) {
-        log::debug!("Setting value to: {}", value);
-        self.value.store(value, Ordering::SeqCst);
+        log_debug!("Setting value to: {}", value);
+        self.value.store(value);
     }
 
     pub fn reset(&self) {
-        log::debug!("Resetting value to min_val: {}", self.min_val);
-        self.value.store(self.min_val, Ordering::SeqCst);
+        log_debug!("Resetting value to min_val: {}", self.min_val);
+        self.value.store(self.min_val);
     }
 
     /// Process rotary encoder pin state changes
     /// This should be called from an interrupt handler
     pub fn process_pins(&self, clk: bool, dt: bool) {
         let old_value = self.value();
-        let old_state = self.state.load(Ordering::SeqCst);
-        
+        let old_state = self.state.load();
+
         // Combine pin states into a 2-bit value
         let clk_dt_pins = ((clk as u8) << 1) | (dt as u8);
-        
-        log::trace!("Pin interrupt: CLK={}, DT={}, combined=0b{:02b}, old_state=0x{:02x}, old_value={}",
+
+        log_trace!("Pin interrupt: CLK={}, DT={}, combined=0b{:02b}, old_state=0x{:02x}, old_value={}",
                    clk, dt, clk_dt_pins, old_state, old_value);
-        
+
         // Determine next state from transition table
         let current_state_index = (old_state & STATE_MASK) as usize;
         let pin_index = clk_dt_pins as usize;
-        
-        if current_state_index >= TRANSITION_TABLE.len() || pin_index >= 4 {
-            log::warn!("Invalid state or pin index: state={}, pins={}", current_state_index, pin_index);
+
+        let table_len = match self.step_mode {
+            StepMode::Full => TRANSITION_TABLE.len(),
+            StepMode::Half => HALF_STEP_TRANSITION_TABLE.len(),
+        };
+
+        if current_state_index >= table_len || pin_index >= 4 {
+            log_warn!("Invalid state or pin index: state={}, pins={}", current_state_index, pin_index);
             return;
         }
-        
-        let new_state = TRANSITION_TABLE[current_state_index][pin_index];
-        self.state.store(new_state, Ordering::SeqCst);
-        
+
+        let new_state = match self.step_mode {
+            StepMode::Full => TRANSITION_TABLE[current_state_index][pin_index],
+            StepMode::Half => HALF_STEP_TRANSITION_TABLE[current_state_index][pin_index],
+        };
+        self.state.store(new_state);
+
         let direction = new_state & DIR_MASK;
-        
-        log::trace!("State transition: 0x{:02x} -> 0x{:02x}, direction=0x{:02x}",
+
+        log_trace!("State transition: 0x{:02x} -> 0x{:02x}, direction=0x{:02x}",
                    old_state, new_state, direction);
-        
-        // Calculate increment based on direction
-        let mut incr = 0;
+
+        // Determine the raw (pre-reverse, pre-steps_per_period) direction of
+        // this Gray-code sub-step.
+        let mut raw_sign = 0;
         if direction == DIR_CW {
-            incr = self.incr;
-            log::debug!("Clockwise rotation detected, increment={}", incr);
+            raw_sign = 1;
+            log_debug!("Clockwise sub-step detected");
         } else if direction == DIR_CCW {
-            incr = -self.incr;
-            log::debug!("Counter-clockwise rotation detected, increment={}", incr);
+            raw_sign = -1;
+            log_debug!("Counter-clockwise sub-step detected");
         }
-        
-        incr *= self.reverse;
-        
+
+        let mut incr = 0;
+
+        if raw_sign != 0 {
+            let steps_per_period = self.steps_per_period.max(1) as i32;
+            let accumulated = self.sub_step_accum.load() + raw_sign * self.reverse;
+
+            if accumulated.abs() >= steps_per_period {
+                // Enough sub-steps have accumulated to make up one logical
+                // step; keep whatever is left over so a direction reversal
+                // mid-detent decrements the accumulator instead of losing it.
+                let periods = accumulated / steps_per_period;
+                self.sub_step_accum
+                    .store(accumulated - periods * steps_per_period);
+                incr = self.incr * periods * self.acceleration_multiplier(periods.signum());
+            } else {
+                self.sub_step_accum.store(accumulated);
+            }
+        }
+
         if incr != 0 {
             // Update value based on range mode
             let new_value = match self.range_mode {
                 RangeMode::Wrap => {
                     let range = self.max_val - self.min_val + 1;
                     let mut val = old_value + incr;
-                    
+
                     if val < self.min_val {
                         val += range * ((self.min_val - val) / range + 1);
                     }
-                    
+
                     self.min_val + (val - self.min_val) % range
                 }
                 RangeMode::Bounded => {
@@ -147,10 +620,25 @@ impl RotaryEncoder {
                 }
                 RangeMode::Unbounded => old_value + incr,
             };
-            
-            self.value.store(new_value, Ordering::SeqCst);
-            
-            log::info!("Value changed: {} -> {} (incr={})", old_value, new_value, incr);
+
+            self.value.store(new_value);
+
+            log_info!("Value changed: {} -> {} (incr={})", old_value, new_value, incr);
+
+            #[cfg(feature = "std")]
+            if let Some(queue) = &self.event_queue {
+                let direction = if incr > 0 {
+                    Direction::Clockwise
+                } else {
+                    Direction::CounterClockwise
+                };
+
+                queue.push(Event::Rotation(EncoderEvent {
+                    direction,
+                    value: new_value,
+                    timestamp: Instant::now(),
+                }));
+            }
         }
     }
     
@@ -160,13 +648,130 @@ impl RotaryEncoder {
     }
 }
 
-#[cfg(test)]
+// Like `AccelState`, this can't take a blocking `Mutex`: `process_button` is
+// documented below to run from an interrupt handler, and a lock held there
+// risks unbounded ISR latency or a deadlock. Every field is a plain atomic,
+// written only by the single ISR driving this button's `process_button`
+// calls; timestamps are stored as nanoseconds since `Button::epoch` (with
+// `NO_TIMESTAMP` for "none yet") since `Instant` itself isn't atomic-sized.
+#[cfg(feature = "std")]
+struct ButtonState {
+    pressed: AtomicBool,
+    last_transition_nanos: AtomicU64,
+    pressed_since_nanos: AtomicU64,
+    long_press_fired: AtomicBool,
+}
+
+/// A debounced momentary push-button, typically the switch built into a
+/// rotary encoder module's shaft. Call `process_button` from its own pin
+/// interrupt handler; events are delivered through the same sink as
+/// rotation, via the `RotaryEncoder` it was attached to.
+///
+/// `process_button` only runs on pin edges, so a button held down with no
+/// further edge never gets a fresh call on its own. To still detect
+/// `LongPress`, poll `process_button` periodically (e.g. from a timer tick
+/// or the main loop) with the button's current, unchanged `pressed` state
+/// while it may be held — `LongPress` still only fires once per press no
+/// matter how many such polls land after the threshold is crossed.
+#[cfg(feature = "std")]
+pub struct Button {
+    debounce_ms: u64,
+    long_press_ms: u64,
+    epoch: Instant,
+    state: ButtonState,
+    queue: Arc<EventQueue>,
+}
+
+#[cfg(feature = "std")]
+impl Button {
+    fn new(queue: Arc<EventQueue>, debounce_ms: u64, long_press_ms: u64) -> Self {
+        Self {
+            debounce_ms,
+            long_press_ms,
+            epoch: Instant::now(),
+            state: ButtonState {
+                pressed: AtomicBool::new(false),
+                last_transition_nanos: AtomicU64::new(NO_TIMESTAMP),
+                pressed_since_nanos: AtomicU64::new(NO_TIMESTAMP),
+                long_press_fired: AtomicBool::new(false),
+            },
+            queue,
+        }
+    }
+
+    fn nanos_since_epoch(&self, instant: Instant) -> u64 {
+        instant.saturating_duration_since(self.epoch).as_nanos() as u64
+    }
+
+    /// Process a raw button pin reading, along with the tick/timestamp it
+    /// was sampled at. Should be called from an interrupt handler, just like
+    /// `process_pins` — see the struct docs above for the polling caveat
+    /// `LongPress` detection relies on.
+    ///
+    /// Transitions within `debounce_ms` of the last accepted transition are
+    /// ignored as bounce. While held, a `LongPress` event fires once the
+    /// button has been down for at least `long_press_ms`; a `Click` fires on
+    /// release if `LongPress` did not already fire for that press.
+    pub fn process_button(&self, pressed: bool, now: Instant) {
+        let now_nanos = self.nanos_since_epoch(now);
+        let was_pressed = self.state.pressed.load(Ordering::SeqCst);
+
+        if pressed == was_pressed {
+            if was_pressed && !self.state.long_press_fired.load(Ordering::SeqCst) {
+                let pressed_since_nanos = self.state.pressed_since_nanos.load(Ordering::SeqCst);
+                if pressed_since_nanos != NO_TIMESTAMP
+                    && now_nanos.saturating_sub(pressed_since_nanos) / 1_000_000
+                        >= self.long_press_ms
+                {
+                    self.state.long_press_fired.store(true, Ordering::SeqCst);
+                    self.queue.push(Event::Button(ButtonEvent::LongPress));
+                }
+            }
+            return;
+        }
+
+        let last_transition_nanos = self.state.last_transition_nanos.load(Ordering::SeqCst);
+        if last_transition_nanos != NO_TIMESTAMP
+            && now_nanos.saturating_sub(last_transition_nanos) / 1_000_000 < self.debounce_ms
+        {
+            // Within the debounce window: treat as bounce, not a real edge.
+            return;
+        }
+
+        self.state
+            .last_transition_nanos
+            .store(now_nanos, Ordering::SeqCst);
+        self.state.pressed.store(pressed, Ordering::SeqCst);
+
+        if pressed {
+            self.state
+                .pressed_since_nanos
+                .store(now_nanos, Ordering::SeqCst);
+            self.state.long_press_fired.store(false, Ordering::SeqCst);
+            self.queue.push(Event::Button(ButtonEvent::Down));
+        } else {
+            self.queue.push(Event::Button(ButtonEvent::Up));
+            if !self.state.long_press_fired.load(Ordering::SeqCst) {
+                self.queue.push(Event::Button(ButtonEvent::Click));
+            }
+            self.state
+                .pressed_since_nanos
+                .store(NO_TIMESTAMP, Ordering::SeqCst);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::*;
+    use std::time::Duration;
 
     #[test]
     fn test_encoder_unbounded() {
-        let encoder = RotaryEncoder::new(0, 100, 1, false, RangeMode::Unbounded);
+        let encoder = RotaryEncoder::new(RotaryEncoderConfig {
+            max_val: 100,
+            ..Default::default()
+        });
         assert_eq!(encoder.value(), 0);
         
         // Simulate clockwise rotation
@@ -178,7 +783,11 @@ mod tests {
 
     #[test]
     fn test_encoder_wrap() {
-        let encoder = RotaryEncoder::new(0, 5, 1, false, RangeMode::Wrap);
+        let encoder = RotaryEncoder::new(RotaryEncoderConfig {
+            max_val: 5,
+            range_mode: RangeMode::Wrap,
+            ..Default::default()
+        });
         encoder.set_value(5);
         
         // Simulate rotation that would go beyond max
@@ -189,7 +798,11 @@ mod tests {
 
     #[test]
     fn test_encoder_bounded() {
-        let encoder = RotaryEncoder::new(0, 10, 1, false, RangeMode::Bounded);
+        let encoder = RotaryEncoder::new(RotaryEncoderConfig {
+            max_val: 10,
+            range_mode: RangeMode::Bounded,
+            ..Default::default()
+        });
         encoder.set_value(10);
         
         // Try to go beyond max - should stay at max
@@ -199,4 +812,255 @@ mod tests {
         
         assert!(encoder.value() <= 10);
     }
+
+    #[test]
+    fn test_encoder_half_step() {
+        let encoder = RotaryEncoder::new(RotaryEncoderConfig {
+            max_val: 100,
+            step_mode: StepMode::Half,
+            ..Default::default()
+        });
+
+        // Clockwise sequence per HALF_STEP_TRANSITION_TABLE: 11 -> 01 -> 00 -> 10 -> 11
+        // In half-step mode this emits a step at both the 00 and 11 rest
+        // positions, so one full cycle should advance the value by 2.
+        encoder.process_pins(true, true);
+        encoder.process_pins(false, true);
+        encoder.process_pins(false, false);
+        encoder.process_pins(true, false);
+        encoder.process_pins(true, true);
+
+        assert_eq!(encoder.value(), 2);
+    }
+
+    #[test]
+    fn test_steps_per_period_accumulates_and_carries_remainder() {
+        let encoder = RotaryEncoder::new(RotaryEncoderConfig {
+            max_val: 100,
+            steps_per_period: 4,
+            ..Default::default()
+        });
+
+        let cw_cycle = |e: &RotaryEncoder| {
+            e.process_pins(true, true);
+            e.process_pins(true, false);
+            e.process_pins(false, false);
+            e.process_pins(false, true);
+            e.process_pins(true, true);
+        };
+        let ccw_cycle = |e: &RotaryEncoder| {
+            e.process_pins(true, true);
+            e.process_pins(false, true);
+            e.process_pins(false, false);
+            e.process_pins(true, false);
+            e.process_pins(true, true);
+        };
+
+        cw_cycle(&encoder);
+        cw_cycle(&encoder);
+        assert_eq!(encoder.value(), 0); // only 2 of 4 sub-steps accumulated
+
+        // Reversing mid-detent should decrement the accumulator, not reset it.
+        ccw_cycle(&encoder);
+        assert_eq!(encoder.value(), 0);
+
+        // One sub-step is still pending; three more clockwise sub-steps complete the period.
+        cw_cycle(&encoder);
+        cw_cycle(&encoder);
+        cw_cycle(&encoder);
+        assert_eq!(encoder.value(), 1);
+    }
+
+    #[test]
+    fn test_acceleration_scales_up_fast_detents_and_resets_on_reverse() {
+        let encoder = RotaryEncoder::new(RotaryEncoderConfig {
+            max_val: 1000,
+            acceleration: Some(Acceleration {
+                threshold_us: 1_000_000,
+                max_multiplier: 5,
+            }),
+            ..Default::default()
+        });
+
+        let cw_cycle = |e: &RotaryEncoder| {
+            e.process_pins(true, true);
+            e.process_pins(true, false);
+            e.process_pins(false, false);
+            e.process_pins(false, true);
+            e.process_pins(true, true);
+        };
+        let ccw_cycle = |e: &RotaryEncoder| {
+            e.process_pins(true, true);
+            e.process_pins(false, true);
+            e.process_pins(false, false);
+            e.process_pins(true, false);
+            e.process_pins(true, true);
+        };
+
+        cw_cycle(&encoder);
+        assert_eq!(encoder.value(), 1); // first detent: no prior timing yet, 1x
+
+        cw_cycle(&encoder);
+        assert_eq!(encoder.value(), 6); // back-to-back: scaled up to max_multiplier (5x)
+
+        ccw_cycle(&encoder);
+        assert_eq!(encoder.value(), 5); // reversing direction resets the multiplier to 1x
+    }
+
+    #[test]
+    fn test_event_sink_reports_detent_direction_and_value() {
+        let (encoder, events) =
+            RotaryEncoder::with_event_sink(RotaryEncoderConfig {
+            max_val: 100,
+            ..Default::default()
+        });
+
+        // Clockwise sequence per TRANSITION_TABLE: 11 -> 10 -> 00 -> 01 -> 11
+        encoder.process_pins(true, true);
+        encoder.process_pins(true, false);
+        encoder.process_pins(false, false);
+        encoder.process_pins(false, true);
+        encoder.process_pins(true, true);
+
+        let event = events.recv().expect("a detent event should be queued");
+        match event {
+            Event::Rotation(rotation) => {
+                assert_eq!(rotation.direction, Direction::Clockwise);
+                assert_eq!(rotation.value, 1);
+            }
+            Event::Button(_) => panic!("expected a rotation event"),
+        }
+        assert!(events.recv().is_none());
+        assert_eq!(events.dropped_events(), 0);
+    }
+
+    #[test]
+    fn test_event_queue_drops_newest_when_full_without_touching_tail() {
+        let queue = EventQueue::new();
+        // Every slot is usable (no head/tail ambiguity to reserve one for),
+        // so all EVENT_QUEUE_CAPACITY pushes below succeed.
+        for _ in 0..EVENT_QUEUE_CAPACITY {
+            queue.push(Event::Button(ButtonEvent::Down));
+        }
+        assert_eq!(queue.dropped(), 0);
+
+        // A full queue must drop the event being pushed, not the oldest one
+        // already queued, since the consumer alone advances `dequeue_pos`.
+        queue.push(Event::Button(ButtonEvent::Up));
+        assert_eq!(queue.dropped(), 1);
+        assert_eq!(queue.pop().unwrap().as_button(), Some(ButtonEvent::Down));
+    }
+
+    #[test]
+    fn test_event_queue_survives_concurrent_multi_producer_pushes() {
+        use std::sync::Barrier;
+        use std::thread;
+
+        // Mirrors `process_pins` and `Button::process_button` sharing one
+        // queue from two independent ISRs: every push must land in a
+        // distinct slot, and every event must be either received or counted
+        // as dropped, never silently lost.
+        let queue = Arc::new(EventQueue::new());
+        const PRODUCERS: usize = 4;
+        const PUSHES_PER_PRODUCER: usize = 2_000;
+        let barrier = Arc::new(Barrier::new(PRODUCERS));
+
+        let handles: Vec<_> = (0..PRODUCERS)
+            .map(|_| {
+                let queue = Arc::clone(&queue);
+                let barrier = Arc::clone(&barrier);
+                thread::spawn(move || {
+                    barrier.wait();
+                    for _ in 0..PUSHES_PER_PRODUCER {
+                        queue.push(Event::Button(ButtonEvent::Down));
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let mut received: u64 = 0;
+        while queue.pop().is_some() {
+            received += 1;
+        }
+
+        assert_eq!(
+            received + queue.dropped(),
+            (PRODUCERS * PUSHES_PER_PRODUCER) as u64
+        );
+    }
+
+    #[test]
+    fn test_button_click_fires_on_quick_release() {
+        let (encoder, events) =
+            RotaryEncoder::with_event_sink(RotaryEncoderConfig {
+            max_val: 100,
+            ..Default::default()
+        });
+        let button = encoder
+            .attach_button(5, 1000)
+            .expect("event sink should be attached");
+
+        let t0 = Instant::now();
+        button.process_button(true, t0);
+        button.process_button(false, t0 + Duration::from_millis(50));
+
+        assert_eq!(events.recv().unwrap().as_button(), Some(ButtonEvent::Down));
+        assert_eq!(events.recv().unwrap().as_button(), Some(ButtonEvent::Up));
+        assert_eq!(events.recv().unwrap().as_button(), Some(ButtonEvent::Click));
+        assert!(events.recv().is_none());
+    }
+
+    #[test]
+    fn test_button_ignores_bounce_within_debounce_window() {
+        let (encoder, events) =
+            RotaryEncoder::with_event_sink(RotaryEncoderConfig {
+            max_val: 100,
+            ..Default::default()
+        });
+        let button = encoder
+            .attach_button(10, 1000)
+            .expect("event sink should be attached");
+
+        let t0 = Instant::now();
+        button.process_button(true, t0);
+        // Bounce back to released well within the debounce window: ignored.
+        button.process_button(false, t0 + Duration::from_millis(2));
+
+        assert_eq!(events.recv().unwrap().as_button(), Some(ButtonEvent::Down));
+        assert!(events.recv().is_none());
+    }
+
+    #[test]
+    fn test_button_long_press_fires_while_still_held() {
+        let (encoder, events) =
+            RotaryEncoder::with_event_sink(RotaryEncoderConfig {
+            max_val: 100,
+            ..Default::default()
+        });
+        let button = encoder
+            .attach_button(5, 100)
+            .expect("event sink should be attached");
+
+        let t0 = Instant::now();
+        button.process_button(true, t0);
+        assert_eq!(events.recv().unwrap().as_button(), Some(ButtonEvent::Down));
+
+        // Still held past the long-press threshold: fires once, not per poll.
+        button.process_button(true, t0 + Duration::from_millis(150));
+        button.process_button(true, t0 + Duration::from_millis(200));
+        assert_eq!(
+            events.recv().unwrap().as_button(),
+            Some(ButtonEvent::LongPress)
+        );
+        assert!(events.recv().is_none());
+
+        // Releasing afterwards does not also emit a Click.
+        button.process_button(false, t0 + Duration::from_millis(250));
+        assert_eq!(events.recv().unwrap().as_button(), Some(ButtonEvent::Up));
+        assert!(events.recv().is_none());
+    }
 }